@@ -17,10 +17,13 @@ use aws_smithy_async::rt::sleep::AsyncSleep;
 use aws_smithy_http::operation::Operation;
 use aws_smithy_http::retry::ClassifyRetry;
 use aws_smithy_types::retry::{ErrorKind, RetryKind};
+use std::borrow::Cow;
+use std::collections::HashMap;
+use std::fmt;
 use std::future::Future;
 use std::pin::Pin;
-use std::sync::Arc;
-use std::time::Duration;
+use std::sync::{Arc, Mutex, OnceLock};
+use std::time::{Duration, Instant};
 use tracing::Instrument;
 
 /// A policy instantiator.
@@ -51,7 +54,16 @@ pub struct Config {
     max_attempts: u32,
     initial_backoff: Duration,
     max_backoff: Duration,
+    max_elapsed_time: Option<Duration>,
     base: fn() -> f64,
+    backoff_factor: f64,
+    jitter: JitterMode,
+    retry_mode: RetryMode,
+    retry_predicate: Option<RetryPredicate>,
+    initial_retry_tokens: usize,
+    retry_cost: usize,
+    no_retry_increment: usize,
+    timeout_retry_cost: usize,
 }
 
 impl Config {
@@ -94,20 +106,166 @@ impl Config {
         self
     }
 
+    /// Override the growth factor used to space successive retries.
+    ///
+    /// Backoff grows as `initial_backoff * factor^n`. The default factor is `2.0`, matching the
+    /// classic exponential scheme; a smaller factor spaces retries more tightly.
+    pub fn with_backoff_factor(mut self, factor: f64) -> Self {
+        self.backoff_factor = factor;
+        self
+    }
+
+    /// Override the [`JitterMode`] applied to the computed backoff.
+    ///
+    /// The default is [`JitterMode::Full`], which matches the behavior of the `standard` retry
+    /// strategy.
+    pub fn with_jitter(mut self, jitter: JitterMode) -> Self {
+        self.jitter = jitter;
+        self
+    }
+
+    /// Override the [`RetryMode`] used by this policy.
+    ///
+    /// The default is [`RetryMode::Standard`]. [`RetryMode::Adaptive`] layers a client-side
+    /// rate limiter on top of the standard behavior that adapts to throttling from the service;
+    /// see [`RetryMode::Adaptive`] for the limits of where the limiter is consulted today.
+    pub fn with_retry_mode(mut self, retry_mode: RetryMode) -> Self {
+        self.retry_mode = retry_mode;
+        self
+    }
+
+    /// Supply a predicate that is consulted after the operation's `ClassifyRetry` implementation.
+    ///
+    /// The closure receives the classified [`RetryKind`] and the current attempt number and returns
+    /// a [`RetryDecision`] that can force a retry, force a stop, or defer to the default behavior.
+    /// This mirrors the ergonomic `retry_if`-style pattern, letting callers tweak retry behavior
+    /// per-client without implementing the `ClassifyRetry` trait:
+    /// ```no_run
+    /// use aws_smithy_client::retry::{Config, RetryDecision};
+    /// use aws_smithy_types::retry::RetryKind;
+    /// let conf = Config::default().with_retry_predicate(|_kind: &RetryKind, attempts: u32| {
+    ///     if attempts > 5 {
+    ///         RetryDecision::DoNotRetry
+    ///     } else {
+    ///         RetryDecision::Default
+    ///     }
+    /// });
+    /// ```
+    pub fn with_retry_predicate(
+        mut self,
+        predicate: impl Fn(&RetryKind, u32) -> RetryDecision + Send + Sync + 'static,
+    ) -> Self {
+        self.retry_predicate = Some(RetryPredicate(Arc::new(predicate)));
+        self
+    }
+
+    /// Cap the cumulative time a request may spend across all retry attempts.
+    ///
+    /// `max_attempts` and `max_backoff` bound each individual hop, but they place no ceiling on the
+    /// total time a request can spend retrying. Setting a budget here makes the worst-case latency
+    /// predictable: a retry is attempted only if sleeping for its candidate backoff would keep the
+    /// cumulative elapsed time within the budget. The backoff is never clamped — a retry that would
+    /// overshoot the deadline is refused outright rather than shortened. In [`RetryMode::Adaptive`]
+    /// the check is made before the rate limiter's send delay is added to the backoff, so that
+    /// delay is not counted against the budget. A [`RetryKind::Explicit`] retry is not checked
+    /// against the budget either.
+    pub fn with_max_elapsed_time(mut self, max_elapsed_time: Duration) -> Self {
+        self.max_elapsed_time = Some(max_elapsed_time);
+        self
+    }
+
     /// Returns true if retry is enabled with this config
     pub fn has_retry(&self) -> bool {
         self.max_attempts > 1
     }
 }
 
+/// The jitter model applied to a computed backoff delay.
+///
+/// Jitter spreads retries out in time so that many clients failing together do not all retry in
+/// lockstep. The variants mirror the strategies implemented by common retry libraries.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum JitterMode {
+    /// `min(random * (initial * factor^n), cap)` — the default "full jitter" scheme.
+    ///
+    /// Note the `min` is applied *after* scaling by the random multiplier (preserving the
+    /// historical behavior), so once the nominal backoff exceeds `cap` the delay pins to `cap`
+    /// more often than a `random(0, min(cap, nominal))` formulation would.
+    Full,
+    /// `half + random(0, half)` where `half = min(cap, initial * factor^n) / 2`.
+    ///
+    /// Guarantees at least half of the nominal backoff while still spreading the remainder.
+    Equal,
+    /// `min(cap, random(initial, prev_sleep * 3))` — "decorrelated jitter".
+    ///
+    /// Each delay is derived from the previous one rather than the attempt number, which tends to
+    /// avoid the clustering that purely attempt-based schemes can exhibit.
+    Decorrelated,
+    /// `delay = base_delay * random(1 - rf, 1 + rf)` with a randomization factor `rf`.
+    ///
+    /// Matches the jitter model used by many HTTP clients. A typical value for `rf` is `0.25`.
+    Randomization(f64),
+}
+
+/// The decision returned by a user-supplied retry predicate.
+///
+/// See [`Config::with_retry_predicate`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum RetryDecision {
+    /// Retry regardless of how the classifier categorized the result.
+    Retry,
+    /// Do not retry, overriding the classifier.
+    DoNotRetry,
+    /// Defer to the default, classification-based decision.
+    Default,
+}
+
+/// A user-supplied predicate that can override retry classification.
+///
+/// Held behind an `Arc` so it can be shared cheaply across every request from one client.
+#[derive(Clone)]
+pub struct RetryPredicate(Arc<dyn Fn(&RetryKind, u32) -> RetryDecision + Send + Sync>);
+
+impl fmt::Debug for RetryPredicate {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("RetryPredicate")
+    }
+}
+
+/// The retry strategy a [`Standard`] policy enforces.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum RetryMode {
+    /// The `standard` strategy: bounded attempts, exponential backoff, and a shared retry quota.
+    Standard,
+    /// The `adaptive` strategy: everything `standard` does, plus a client-side rate limiter that
+    /// adapts its fill rate to observed throttling and gates the rate of request *sends*.
+    ///
+    /// A [tower retry policy](tower::retry::Policy) is only consulted once a response has been
+    /// classified, so the limiter is consulted from there: each retryable error feeds back into the
+    /// limiter's fill rate (a throttling error decreases it) and adds the limiter's send delay to
+    /// that retry's backoff, and each success grows the fill rate back. The first attempt of a
+    /// request is not throttled: [`RetryHandler::acquire_permit`] returns the limiter's delay for
+    /// an attempt, but nothing in this crate calls it before dispatching.
+    Adaptive,
+}
+
 impl Default for Config {
     fn default() -> Self {
         Self {
             max_attempts: MAX_ATTEMPTS,
             max_backoff: Duration::from_secs(20),
+            max_elapsed_time: None,
             // by default, use a random base for exponential backoff
             base: fastrand::f64,
+            backoff_factor: 2.0,
+            jitter: JitterMode::Full,
+            retry_mode: RetryMode::Standard,
+            retry_predicate: None,
             initial_backoff: Duration::from_secs(1),
+            initial_retry_tokens: INITIAL_RETRY_TOKENS,
+            retry_cost: RETRY_COST,
+            no_retry_increment: PERMIT_REFILL,
+            timeout_retry_cost: RETRY_TIMEOUT_COST,
         }
     }
 }
@@ -122,6 +280,15 @@ impl From<aws_smithy_types::retry::RetryConfig> for Config {
 
 const MAX_ATTEMPTS: u32 = 3;
 
+/// The number of tokens the retry quota is seeded with for a fresh partition.
+const INITIAL_RETRY_TOKENS: usize = 500;
+/// The number of tokens withdrawn for a normal retryable error.
+const RETRY_COST: usize = 5;
+/// The number of tokens withdrawn for a timeout/transient error.
+const RETRY_TIMEOUT_COST: usize = RETRY_COST * 2;
+/// The number of tokens returned to the quota after a request that did not retry.
+const PERMIT_REFILL: usize = 1;
+
 /// Manage retries for a service
 ///
 /// An implementation of the `standard` AWS retry strategy. A `Strategy` is scoped to a client.
@@ -129,12 +296,43 @@ const MAX_ATTEMPTS: u32 = 3;
 #[derive(Debug, Clone)]
 pub struct Standard {
     config: Config,
+    shared: CrossRequestRetryState,
+    /// Shared client-side rate limiter, present only in [`RetryMode::Adaptive`].
+    rate_limiter: Option<ClientRateLimiter>,
 }
 
 impl Standard {
     /// Construct a new standard retry policy from the given policy configuration.
+    ///
+    /// The retry quota created here is local to this `Standard`. To share a quota across several
+    /// clients of the same service, construct them with [`Standard::with_partition`].
     pub fn new(config: Config) -> Self {
-        Self { config }
+        let shared = CrossRequestRetryState::new(config.initial_retry_tokens);
+        Self::with_shared_state(config, shared)
+    }
+
+    /// Construct a new standard retry policy scoped to the given [`RetryPartition`].
+    ///
+    /// The retry quota is keyed by partition name in a process-wide registry: every `Standard`
+    /// built with the same [`RetryPartition`] draws from one shared budget, while distinct
+    /// partitions remain independent so a storm of failures in one service cannot exhaust another
+    /// service's quota. The budget is seeded from `initial_retry_tokens` the first time a partition
+    /// is seen.
+    pub fn with_partition(config: Config, partition: RetryPartition) -> Self {
+        let shared = partition_quota(&partition, config.initial_retry_tokens);
+        Self::with_shared_state(config, shared)
+    }
+
+    fn with_shared_state(config: Config, shared: CrossRequestRetryState) -> Self {
+        let rate_limiter = match config.retry_mode {
+            RetryMode::Standard => None,
+            RetryMode::Adaptive => Some(ClientRateLimiter::new(now_seconds())),
+        };
+        Self {
+            config,
+            shared,
+            rate_limiter,
+        }
     }
 
     /// Set the configuration for this retry policy.
@@ -151,6 +349,8 @@ impl NewRequestPolicy for Standard {
         RetryHandler {
             local: RequestLocalRetryState::new(),
             config: self.config.clone(),
+            shared: self.shared.clone(),
+            rate_limiter: self.rate_limiter.clone(),
             sleep_impl,
         }
     }
@@ -165,6 +365,14 @@ impl Default for Standard {
 #[derive(Clone, Debug)]
 struct RequestLocalRetryState {
     attempts: u32,
+    /// Tokens withdrawn from the shared quota on the most recent retry, refunded on success.
+    last_quota_usage: Option<usize>,
+    /// The backoff used for the previous retry, threaded through for decorrelated jitter.
+    prev_backoff: Option<Duration>,
+    /// When the request was first constructed, used to enforce the total elapsed-time budget.
+    started_at: Instant,
+    /// The cumulative backoff scheduled so far across all attempts for this request.
+    accumulated_delay: Duration,
 }
 
 impl Default for RequestLocalRetryState {
@@ -172,6 +380,10 @@ impl Default for RequestLocalRetryState {
         Self {
             // Starts at one to account for the initial request that failed and warranted a retry
             attempts: 1,
+            last_quota_usage: None,
+            prev_backoff: None,
+            started_at: Instant::now(),
+            accumulated_delay: Duration::ZERO,
         }
     }
 }
@@ -182,11 +394,248 @@ impl RequestLocalRetryState {
     }
 }
 
-/* TODO(retries)
 /// RetryPartition represents a scope for cross request retry state
 ///
-/// For example, a retry partition could be the id of a service. This would give each service a separate retry budget.
-struct RetryPartition(Cow<'static, str>); */
+/// For example, a retry partition could be the id of a service. This would give each service a
+/// separate retry budget.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct RetryPartition(Cow<'static, str>);
+
+impl RetryPartition {
+    /// Create a new `RetryPartition` from the given name.
+    pub fn new(name: impl Into<Cow<'static, str>>) -> Self {
+        Self(name.into())
+    }
+}
+
+impl Default for RetryPartition {
+    fn default() -> Self {
+        Self(Cow::Borrowed("default"))
+    }
+}
+
+/// Fetch the shared retry quota for `partition`, creating it seeded at `initial_quota` the first
+/// time the partition is seen. Every caller passing the same partition gets a handle to the same
+/// underlying token bucket.
+fn partition_quota(partition: &RetryPartition, initial_quota: usize) -> CrossRequestRetryState {
+    static REGISTRY: OnceLock<Mutex<HashMap<RetryPartition, CrossRequestRetryState>>> =
+        OnceLock::new();
+    let mut registry = REGISTRY
+        .get_or_init(|| Mutex::new(HashMap::new()))
+        .lock()
+        .unwrap();
+    registry
+        .entry(partition.clone())
+        .or_insert_with(|| CrossRequestRetryState::new(initial_quota))
+        .clone()
+}
+
+/// Cross-request retry state shared by all requests served by a [`Standard`] policy.
+///
+/// Implements the `standard` retry quota: a token bucket seeded at a fixed balance that each
+/// retry must withdraw from and that successful requests replenish. Once exhausted, further
+/// retries are refused until enough successes have deposited tokens back.
+#[derive(Clone, Debug)]
+struct CrossRequestRetryState {
+    max_permits: usize,
+    quota_available: Arc<Mutex<usize>>,
+}
+
+impl CrossRequestRetryState {
+    fn new(initial_quota: usize) -> Self {
+        Self {
+            max_permits: initial_quota,
+            quota_available: Arc::new(Mutex::new(initial_quota)),
+        }
+    }
+
+    /// Attempt to withdraw `amount` tokens from the bucket.
+    ///
+    /// Returns the number of tokens withdrawn, or `None` if the balance would go negative.
+    fn withdraw(&self, amount: usize) -> Option<usize> {
+        let mut quota = self.quota_available.lock().unwrap();
+        if amount > *quota {
+            return None;
+        }
+        *quota -= amount;
+        Some(amount)
+    }
+
+    /// Return `amount` tokens to the bucket, saturating at the initial quota.
+    fn deposit(&self, amount: usize) {
+        let mut quota = self.quota_available.lock().unwrap();
+        *quota = (*quota + amount).min(self.max_permits);
+    }
+
+    fn quota_available(&self) -> usize {
+        *self.quota_available.lock().unwrap()
+    }
+}
+
+/// Smoothing factor applied to the exponentially-weighted measured send rate.
+const RATE_SMOOTH: f64 = 0.8;
+/// Multiplicative decrease applied to the fill rate on a throttling response.
+const RATE_BETA: f64 = 0.7;
+/// Scaling constant for the cubic growth curve.
+const RATE_SCALE_CONSTANT: f64 = 0.4;
+/// The smallest fill rate the limiter will throttle down to.
+const RATE_MIN_FILL: f64 = 0.5;
+
+/// Current wall-clock time as fractional seconds since the Unix epoch.
+fn now_seconds() -> f64 {
+    std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .unwrap_or_default()
+        .as_secs_f64()
+}
+
+/// Client-side token-bucket rate limiter backing [`RetryMode::Adaptive`].
+///
+/// Unlike the retry quota, this limiter throttles the *rate of request sends* rather than the
+/// number of retries. It adapts the fill rate to downstream capacity: a throttling response
+/// triggers a multiplicative decrease, and subsequent successes grow the rate back along a cubic
+/// curve. Shared across every request originating from one client.
+#[derive(Clone, Debug)]
+struct ClientRateLimiter {
+    inner: Arc<Mutex<RateLimiterState>>,
+}
+
+#[derive(Debug)]
+struct RateLimiterState {
+    /// Whether rate limiting is active; enabled on the first observed throttling response.
+    enabled: bool,
+    /// Tokens accrued per second.
+    fill_rate: f64,
+    /// Maximum number of tokens the bucket can hold.
+    max_capacity: f64,
+    /// Tokens currently available in the bucket.
+    current_capacity: f64,
+    /// Timestamp of the last refill, in seconds since the epoch.
+    last_timestamp: Option<f64>,
+    /// Exponentially-smoothed rate at which requests are being sent.
+    measured_tx_rate: f64,
+    /// Start of the half-second bucket currently accumulating the send count.
+    last_tx_rate_bucket: f64,
+    /// Requests observed in the current bucket.
+    request_count: u64,
+    /// The measured rate at the time of the last throttling response.
+    last_max_rate: f64,
+    /// Timestamp of the last throttling response.
+    last_throttle_time: f64,
+    /// The cubic time window derived from `last_max_rate`.
+    time_window: f64,
+}
+
+impl ClientRateLimiter {
+    fn new(now: f64) -> Self {
+        Self {
+            inner: Arc::new(Mutex::new(RateLimiterState {
+                enabled: false,
+                fill_rate: RATE_MIN_FILL,
+                max_capacity: f64::MAX,
+                current_capacity: 0.0,
+                last_timestamp: None,
+                measured_tx_rate: 0.0,
+                last_tx_rate_bucket: (now * 2.0).floor() / 2.0,
+                request_count: 0,
+                last_max_rate: 0.0,
+                last_throttle_time: now,
+                time_window: 0.0,
+            })),
+        }
+    }
+
+    /// Acquire `amount` tokens, returning the [`Duration`] the caller must sleep first.
+    ///
+    /// Refills the bucket for the elapsed time, then — if there aren't enough tokens — returns the
+    /// delay required to accrue the shortfall at the current fill rate. The tokens are always
+    /// debited so that concurrent callers observe the contention.
+    fn acquire(&self, now: f64, amount: f64) -> Duration {
+        let mut state = self.inner.lock().unwrap();
+        if !state.enabled {
+            return Duration::ZERO;
+        }
+        state.refill(now);
+        let sleep = if amount > state.current_capacity {
+            Duration::from_secs_f64((amount - state.current_capacity) / state.fill_rate)
+        } else {
+            Duration::ZERO
+        };
+        state.current_capacity -= amount;
+        sleep
+    }
+
+    /// Update the limiter in response to an attempt's outcome.
+    fn update(&self, now: f64, is_throttling: bool) {
+        let mut state = self.inner.lock().unwrap();
+        state.update_measured_rate(now);
+
+        let calculated_rate = if is_throttling {
+            let rate_to_use = if state.enabled {
+                state.measured_tx_rate.min(state.fill_rate)
+            } else {
+                state.measured_tx_rate
+            };
+            state.last_max_rate = rate_to_use;
+            state.calculate_time_window();
+            state.last_throttle_time = now;
+            state.enabled = true;
+            // Cubic decrease.
+            rate_to_use * RATE_BETA
+        } else {
+            state.calculate_time_window();
+            // Cubic increase, capped at a linear probe of twice the measured rate below.
+            state.cubic_success(now)
+        };
+
+        let new_rate = calculated_rate.min(2.0 * state.measured_tx_rate);
+        state.update_fill_rate(now, new_rate);
+    }
+}
+
+impl RateLimiterState {
+    /// Add tokens accrued since the last refill, saturating at `max_capacity`.
+    fn refill(&mut self, now: f64) {
+        if let Some(last) = self.last_timestamp {
+            let fill = (now - last) * self.fill_rate;
+            self.current_capacity = (self.current_capacity + fill).min(self.max_capacity);
+        }
+        self.last_timestamp = Some(now);
+    }
+
+    /// Refill against `now` at the old fill rate, then recompute the fill rate and capacity.
+    fn update_fill_rate(&mut self, now: f64, new_fill_rate: f64) {
+        self.refill(now);
+        self.fill_rate = new_fill_rate.max(RATE_MIN_FILL);
+        self.max_capacity = new_fill_rate.max(RATE_MIN_FILL);
+        self.current_capacity = self.current_capacity.min(self.max_capacity);
+    }
+
+    /// Recompute the cubic time window from the last observed max rate.
+    fn calculate_time_window(&mut self) {
+        self.time_window = ((self.last_max_rate * (1.0 - RATE_BETA)) / RATE_SCALE_CONSTANT).cbrt();
+    }
+
+    /// The cubic growth curve evaluated at `now`.
+    fn cubic_success(&self, now: f64) -> f64 {
+        let dt = now - self.last_throttle_time - self.time_window;
+        RATE_SCALE_CONSTANT * dt.powi(3) + self.last_max_rate
+    }
+
+    /// Fold the latest send into the exponentially-smoothed measured send rate.
+    fn update_measured_rate(&mut self, now: f64) {
+        let next_bucket = (now * 2.0).floor() / 2.0;
+        self.request_count += 1;
+        if next_bucket > self.last_tx_rate_bucket {
+            let current_rate =
+                self.request_count as f64 / (next_bucket - self.last_tx_rate_bucket);
+            self.measured_tx_rate =
+                current_rate * RATE_SMOOTH + self.measured_tx_rate * (1.0 - RATE_SMOOTH);
+            self.request_count = 0;
+            self.last_tx_rate_bucket = next_bucket;
+        }
+    }
+}
 
 type BoxFuture<T> = Pin<Box<dyn Future<Output = T> + Send>>;
 
@@ -199,6 +648,8 @@ type BoxFuture<T> = Pin<Box<dyn Future<Output = T> + Send>>;
 pub struct RetryHandler {
     local: RequestLocalRetryState,
     config: Config,
+    shared: CrossRequestRetryState,
+    rate_limiter: Option<ClientRateLimiter>,
     sleep_impl: Option<Arc<dyn AsyncSleep>>,
 }
 
@@ -211,8 +662,13 @@ pub struct RetryHandler {
 /// - the first retry will occur after 0 to 30 milliseconds
 /// - the second retry will occur after 0 to 60 milliseconds
 /// - the third retry will occur after 0 to 120 milliseconds
-fn calculate_exponential_backoff(base: f64, initial_backoff: f64, retry_attempts: u32) -> f64 {
-    base * initial_backoff * 2_u32.pow(retry_attempts) as f64
+fn calculate_exponential_backoff(
+    base: f64,
+    initial_backoff: f64,
+    retry_attempts: u32,
+    factor: f64,
+) -> f64 {
+    base * initial_backoff * factor.powi(retry_attempts as i32)
 }
 
 impl RetryHandler {
@@ -220,37 +676,167 @@ impl RetryHandler {
     ///
     /// If a retry is specified, this function returns `(next, backoff_duration)`
     /// If no retry is specified, this function returns None
-    fn should_retry_error(&self) -> Option<(Self, Duration)> {
+    fn should_retry_error(&self, error_kind: &ErrorKind) -> Option<(Self, Duration)> {
         if self.local.attempts == self.config.max_attempts {
             return None;
         }
-        let backoff = calculate_exponential_backoff(
-            // Generate a random base multiplier to create jitter
-            (self.config.base)(),
-            // Get the backoff time multiplier in seconds (with fractional seconds)
-            self.config.initial_backoff.as_secs_f64(),
-            // `self.local.attempts` tracks number of requests made including the initial request
-            // The initial attempt shouldn't count towards backoff calculations so we subtract it
-            self.local.attempts - 1,
-        );
-        let backoff = Duration::from_secs_f64(backoff).min(self.config.max_backoff);
+        let mut backoff = self.calculate_backoff();
+        // Enforce the total elapsed-time budget before committing any shared state: if sleeping for
+        // the candidate backoff would carry the cumulative elapsed time past the deadline, refuse
+        // the retry outright rather than attempt one that would overshoot.
+        if let Some(max_elapsed) = self.config.max_elapsed_time {
+            if self.local.started_at.elapsed() + backoff > max_elapsed {
+                return None;
+            }
+        }
+        // Attempt to withdraw tokens from the shared quota before committing to a retry. A
+        // transient/timeout error costs more than a normal retryable error, and if the balance
+        // would go negative we refuse the retry entirely.
+        let retry_cost = if error_kind == &ErrorKind::TransientError {
+            self.config.timeout_retry_cost
+        } else {
+            self.config.retry_cost
+        };
+        let quota_used = self.shared.withdraw(retry_cost)?;
+        // In adaptive mode, feed the outcome to the client-side rate limiter and fold any
+        // required send-delay into the backoff so we respect the adapted send rate.
+        if let Some(rate_limiter) = &self.rate_limiter {
+            let now = now_seconds();
+            rate_limiter.update(now, error_kind == &ErrorKind::ThrottlingError);
+            backoff += rate_limiter.acquire(now, 1.0);
+        }
         let next = RetryHandler {
             local: RequestLocalRetryState {
                 attempts: self.local.attempts + 1,
+                last_quota_usage: Some(quota_used),
+                prev_backoff: Some(backoff),
+                started_at: self.local.started_at,
+                accumulated_delay: self.local.accumulated_delay + backoff,
             },
             config: self.config.clone(),
+            shared: self.shared.clone(),
+            rate_limiter: self.rate_limiter.clone(),
             sleep_impl: self.sleep_impl.clone(),
         };
 
         Some((next, backoff))
     }
 
+    /// Compute the backoff for the current attempt according to the configured [`JitterMode`].
+    fn calculate_backoff(&self) -> Duration {
+        // Generate a random base multiplier to create jitter.
+        let random = (self.config.base)();
+        let initial_backoff = self.config.initial_backoff.as_secs_f64();
+        let cap = self.config.max_backoff.as_secs_f64();
+        // `self.local.attempts` tracks number of requests made including the initial request.
+        // The initial attempt shouldn't count towards backoff calculations so we subtract it.
+        let exp = self.local.attempts - 1;
+
+        let backoff = match self.config.jitter {
+            JitterMode::Full => calculate_exponential_backoff(
+                random,
+                initial_backoff,
+                exp,
+                self.config.backoff_factor,
+            )
+            .min(cap),
+            JitterMode::Equal => {
+                let half = calculate_exponential_backoff(
+                    1.0,
+                    initial_backoff,
+                    exp,
+                    self.config.backoff_factor,
+                )
+                .min(cap)
+                    / 2.0;
+                half + random * half
+            }
+            JitterMode::Decorrelated => {
+                let prev = self
+                    .local
+                    .prev_backoff
+                    .map(|d| d.as_secs_f64())
+                    .unwrap_or(initial_backoff);
+                (initial_backoff + random * (prev * 3.0 - initial_backoff)).min(cap)
+            }
+            JitterMode::Randomization(rf) => {
+                let base_delay = calculate_exponential_backoff(
+                    1.0,
+                    initial_backoff,
+                    exp,
+                    self.config.backoff_factor,
+                )
+                .min(cap);
+                base_delay * (1.0 - rf + random * 2.0 * rf)
+            }
+        };
+
+        Duration::from_secs_f64(backoff)
+    }
+
+    /// Returns the number of retry tokens currently available in the shared quota.
+    pub fn retry_quota(&self) -> usize {
+        self.shared.quota_available()
+    }
+
+    /// Acquire a permit to send a request under the adaptive rate limiter.
+    ///
+    /// Returns the [`Duration`] the caller must sleep before dispatching so that the client-side
+    /// send rate stays within the limiter's adapted fill rate. The send path should call this
+    /// before *every* attempt — including the first — to throttle the overall rate of request
+    /// sends. Returns [`Duration::ZERO`] in [`RetryMode::Standard`], or in [`RetryMode::Adaptive`]
+    /// before the limiter has observed any throttling, so it is safe to call unconditionally.
+    pub fn acquire_permit(&self) -> Duration {
+        match &self.rate_limiter {
+            Some(rate_limiter) => rate_limiter.acquire(now_seconds(), 1.0),
+            None => Duration::ZERO,
+        }
+    }
+
+    /// Returns the cumulative backoff scheduled so far across all attempts for this request.
+    ///
+    /// The delay of a [`RetryKind::Explicit`] retry is not included.
+    ///
+    /// Useful for observing how much delay a request has accrued relative to any configured
+    /// [`max_elapsed_time`](Config::with_max_elapsed_time) budget.
+    pub fn total_delay(&self) -> Duration {
+        self.local.accumulated_delay
+    }
+
     fn should_retry(&self, retry_kind: &RetryKind) -> Option<(Self, Duration)> {
+        // Consult the predicate *before* the classifier path so that forcing a stop doesn't run
+        // the classifier's side effects (debiting the shared quota, updating the rate limiter).
+        match &self.config.retry_predicate {
+            Some(predicate) => match (predicate.0)(retry_kind, self.local.attempts) {
+                // Drive the retry directly through the error path (which still respects the attempt
+                // cap and retry quota) rather than classify-then-discard.
+                RetryDecision::Retry => self.should_retry_error(&ErrorKind::ServerError),
+                RetryDecision::DoNotRetry => None,
+                RetryDecision::Default => self.should_retry_classified(retry_kind),
+            },
+            None => self.should_retry_classified(retry_kind),
+        }
+    }
+
+    fn should_retry_classified(&self, retry_kind: &RetryKind) -> Option<(Self, Duration)> {
         match retry_kind {
             RetryKind::Explicit(dur) => Some((self.clone(), *dur)),
             RetryKind::UnretryableFailure => None,
-            RetryKind::Unnecessary => None,
-            RetryKind::Error(_) => self.should_retry_error(),
+            RetryKind::Unnecessary => {
+                // A successful outcome replenishes the quota: refund the tokens withdrawn by the
+                // last retry, or deposit a small fixed amount if the request never retried.
+                self.shared.deposit(
+                    self.local
+                        .last_quota_usage
+                        .unwrap_or(self.config.no_retry_increment),
+                );
+                // A successful send grows the adaptive rate limiter back along its cubic curve.
+                if let Some(rate_limiter) = &self.rate_limiter {
+                    rate_limiter.update(now_seconds(), false);
+                }
+                None
+            }
+            RetryKind::Error(err) => self.should_retry_error(err),
             _ => None,
         }
     }
@@ -314,7 +900,10 @@ fn check_send<T: Send>(t: T) -> T {
 
 #[cfg(test)]
 mod test {
-    use super::{calculate_exponential_backoff, Config, NewRequestPolicy, RetryHandler, Standard};
+    use super::{
+        calculate_exponential_backoff, ClientRateLimiter, Config, JitterMode, NewRequestPolicy,
+        RetryDecision, RetryHandler, RetryPartition, Standard,
+    };
     use aws_smithy_types::retry::{ErrorKind, RetryKind};
     use std::time::Duration;
 
@@ -336,17 +925,17 @@ mod test {
             .should_retry(&RetryKind::Error(ErrorKind::ServerError))
             .expect("should retry");
         assert_eq!(dur, Duration::from_secs(1));
-        // assert_eq!(policy.retry_quota(), 495);
+        assert_eq!(policy.retry_quota(), 495);
 
         let (policy, dur) = policy
             .should_retry(&RetryKind::Error(ErrorKind::ServerError))
             .expect("should retry");
         assert_eq!(dur, Duration::from_secs(2));
-        // assert_eq!(policy.retry_quota(), 490);
+        assert_eq!(policy.retry_quota(), 490);
 
         let no_retry = policy.should_retry(&RetryKind::Unnecessary);
         assert!(no_retry.is_none());
-        // assert_eq!(policy.retry_quota(), 495);
+        assert_eq!(policy.retry_quota(), 495);
     }
 
     #[test]
@@ -356,64 +945,66 @@ mod test {
             .should_retry(&RetryKind::Error(ErrorKind::ServerError))
             .expect("should retry");
         assert_eq!(dur, Duration::from_secs(1));
-        // assert_eq!(policy.retry_quota(), 495);
+        assert_eq!(policy.retry_quota(), 495);
 
         let (policy, dur) = policy
             .should_retry(&RetryKind::Error(ErrorKind::ServerError))
             .expect("should retry");
         assert_eq!(dur, Duration::from_secs(2));
-        // assert_eq!(policy.retry_quota(), 490);
+        assert_eq!(policy.retry_quota(), 490);
 
         let no_retry = policy.should_retry(&RetryKind::Error(ErrorKind::ServerError));
         assert!(no_retry.is_none());
-        // assert_eq!(policy.retry_quota(), 490);
+        assert_eq!(policy.retry_quota(), 490);
     }
 
     #[test]
     fn no_quota() {
-        let conf = test_config();
+        let mut conf = test_config();
+        conf.initial_retry_tokens = 5;
         let policy = Standard::new(conf).new_request_policy(None);
 
         let (policy, dur) = policy
             .should_retry(&RetryKind::Error(ErrorKind::ServerError))
             .expect("should retry");
         assert_eq!(dur, Duration::from_secs(1));
-        // assert_eq!(policy.retry_quota(), 0);
+        assert_eq!(policy.retry_quota(), 0);
 
         let no_retry = policy.should_retry(&RetryKind::Error(ErrorKind::ServerError));
         assert!(no_retry.is_none());
-        // assert_eq!(policy.retry_quota(), 0);
+        assert_eq!(policy.retry_quota(), 0);
     }
 
     #[test]
     fn quota_replenishes_on_success() {
-        let conf = test_config();
+        let mut conf = test_config();
+        conf.initial_retry_tokens = 100;
         let policy = Standard::new(conf).new_request_policy(None);
         let (policy, dur) = policy
             .should_retry(&RetryKind::Error(ErrorKind::TransientError))
             .expect("should retry");
         assert_eq!(dur, Duration::from_secs(1));
-        // assert_eq!(policy.retry_quota(), 90);
+        assert_eq!(policy.retry_quota(), 90);
 
         let (policy, dur) = policy
             .should_retry(&RetryKind::Explicit(Duration::from_secs(1)))
             .expect("should retry");
         assert_eq!(dur, Duration::from_secs(1));
-        // assert_eq!(
-        //     policy.retry_quota(),
-        //     90,
-        //     "explicit retry should not subtract from quota"
-        // );
+        assert_eq!(
+            policy.retry_quota(),
+            90,
+            "explicit retry should not subtract from quota"
+        );
 
         assert!(
             policy.should_retry(&RetryKind::Unnecessary).is_none(),
             "it should not retry success"
         );
-        // assert_eq!(
-        //     100,
-        //     policy.retry_quota(),
-        //     "successful request should replenish quota"
-        // );
+        assert_eq!(
+            100,
+            policy.retry_quota(),
+            "successful request should replenish quota"
+        );
     }
 
     #[test]
@@ -425,29 +1016,29 @@ mod test {
             .should_retry(&RetryKind::Error(ErrorKind::ServerError))
             .expect("should retry");
         assert_eq!(dur, Duration::from_secs(1));
-        // assert_eq!(policy.retry_quota(), 495);
+        assert_eq!(policy.retry_quota(), 495);
 
         let (policy, dur) = policy
             .should_retry(&RetryKind::Error(ErrorKind::ServerError))
             .expect("should retry");
         assert_eq!(dur, Duration::from_secs(2));
-        // assert_eq!(policy.retry_quota(), 490);
+        assert_eq!(policy.retry_quota(), 490);
 
         let (policy, dur) = policy
             .should_retry(&RetryKind::Error(ErrorKind::ServerError))
             .expect("should retry");
         assert_eq!(dur, Duration::from_secs(4));
-        // assert_eq!(policy.retry_quota(), 485);
+        assert_eq!(policy.retry_quota(), 485);
 
         let (policy, dur) = policy
             .should_retry(&RetryKind::Error(ErrorKind::ServerError))
             .expect("should retry");
         assert_eq!(dur, Duration::from_secs(8));
-        // assert_eq!(policy.retry_quota(), 480);
+        assert_eq!(policy.retry_quota(), 480);
 
         let no_retry = policy.should_retry(&RetryKind::Error(ErrorKind::ServerError));
         assert!(no_retry.is_none());
-        // assert_eq!(policy.retry_quota(), 480);
+        assert_eq!(policy.retry_quota(), 480);
     }
 
     #[test]
@@ -461,29 +1052,180 @@ mod test {
             .should_retry(&RetryKind::Error(ErrorKind::ServerError))
             .expect("should retry");
         assert_eq!(dur, Duration::from_secs(1));
-        // assert_eq!(policy.retry_quota(), 495);
+        assert_eq!(policy.retry_quota(), 495);
 
         let (policy, dur) = policy
             .should_retry(&RetryKind::Error(ErrorKind::ServerError))
             .expect("should retry");
         assert_eq!(dur, Duration::from_secs(2));
-        // assert_eq!(policy.retry_quota(), 490);
+        assert_eq!(policy.retry_quota(), 490);
 
         let (policy, dur) = policy
             .should_retry(&RetryKind::Error(ErrorKind::ServerError))
             .expect("should retry");
         assert_eq!(dur, Duration::from_secs(3));
-        // assert_eq!(policy.retry_quota(), 485);
+        assert_eq!(policy.retry_quota(), 485);
 
         let (policy, dur) = policy
             .should_retry(&RetryKind::Error(ErrorKind::ServerError))
             .expect("should retry");
         assert_eq!(dur, Duration::from_secs(3));
-        // assert_eq!(policy.retry_quota(), 480);
+        assert_eq!(policy.retry_quota(), 480);
 
         let no_retry = policy.should_retry(&RetryKind::Error(ErrorKind::ServerError));
         assert!(no_retry.is_none());
-        // assert_eq!(policy.retry_quota(), 480);
+        assert_eq!(policy.retry_quota(), 480);
+    }
+
+    #[test]
+    fn custom_backoff_factor() {
+        let policy = Standard::new(test_config().with_backoff_factor(3.0)).new_request_policy(None);
+        let (policy, dur) = policy
+            .should_retry(&RetryKind::Error(ErrorKind::ServerError))
+            .expect("should retry");
+        assert_eq!(dur, Duration::from_secs(1));
+
+        let (_policy, dur) = policy
+            .should_retry(&RetryKind::Error(ErrorKind::ServerError))
+            .expect("should retry");
+        assert_eq!(dur, Duration::from_secs(3));
+    }
+
+    #[test]
+    fn equal_jitter() {
+        let conf = Config::default()
+            .with_base(|| 0.0)
+            .with_jitter(JitterMode::Equal);
+        let policy = Standard::new(conf).new_request_policy(None);
+        let (policy, dur) = policy
+            .should_retry(&RetryKind::Error(ErrorKind::ServerError))
+            .expect("should retry");
+        // With no random component, equal jitter yields exactly half the nominal backoff.
+        assert_eq!(dur, Duration::from_millis(500));
+
+        let (_policy, dur) = policy
+            .should_retry(&RetryKind::Error(ErrorKind::ServerError))
+            .expect("should retry");
+        assert_eq!(dur, Duration::from_secs(1));
+    }
+
+    #[test]
+    fn decorrelated_jitter() {
+        let conf = Config::default()
+            .with_base(|| 1.0)
+            .with_jitter(JitterMode::Decorrelated);
+        let policy = Standard::new(conf).new_request_policy(None);
+        // prev is seeded with initial_backoff, so sleep = initial + (initial * 3 - initial) = 3s.
+        let (policy, dur) = policy
+            .should_retry(&RetryKind::Error(ErrorKind::ServerError))
+            .expect("should retry");
+        assert_eq!(dur, Duration::from_secs(3));
+
+        // Derived from the previous sleep: initial + (prev * 3 - initial) = 1 + (9 - 1) = 9s.
+        let (_policy, dur) = policy
+            .should_retry(&RetryKind::Error(ErrorKind::ServerError))
+            .expect("should retry");
+        assert_eq!(dur, Duration::from_secs(9));
+    }
+
+    #[test]
+    fn shared_partition_shares_quota() {
+        let partition = RetryPartition::new("shared-service");
+        let policy_a =
+            Standard::with_partition(test_config(), partition.clone()).new_request_policy(None);
+        let policy_b = Standard::with_partition(test_config(), partition).new_request_policy(None);
+
+        // A retry withdrawn through one client's policy is visible to the other: both draw from a
+        // single budget keyed by the partition name.
+        let before = policy_b.retry_quota();
+        let _ = policy_a
+            .should_retry(&RetryKind::Error(ErrorKind::ServerError))
+            .expect("should retry");
+        assert_eq!(policy_b.retry_quota(), before - 5);
+    }
+
+    #[test]
+    fn tracks_total_delay() {
+        let mut conf = test_config();
+        conf.max_attempts = 5;
+        let policy = Standard::new(conf).new_request_policy(None);
+        assert_eq!(policy.total_delay(), Duration::ZERO);
+
+        let (policy, _) = policy
+            .should_retry(&RetryKind::Error(ErrorKind::ServerError))
+            .expect("should retry");
+        assert_eq!(policy.total_delay(), Duration::from_secs(1));
+
+        let (policy, _) = policy
+            .should_retry(&RetryKind::Error(ErrorKind::ServerError))
+            .expect("should retry");
+        assert_eq!(policy.total_delay(), Duration::from_secs(3));
+    }
+
+    #[test]
+    fn max_elapsed_time_refuses_overshooting_retry() {
+        let mut conf = test_config().with_max_elapsed_time(Duration::from_secs(3));
+        conf.max_attempts = 5;
+        let policy = Standard::new(conf).new_request_policy(None);
+
+        // 1s backoff fits within the 3s budget.
+        let (policy, dur) = policy
+            .should_retry(&RetryKind::Error(ErrorKind::ServerError))
+            .expect("should retry");
+        assert_eq!(dur, Duration::from_secs(1));
+
+        // 2s backoff still fits (elapsed is ~0 in a synchronous test).
+        let (policy, dur) = policy
+            .should_retry(&RetryKind::Error(ErrorKind::ServerError))
+            .expect("should retry");
+        assert_eq!(dur, Duration::from_secs(2));
+
+        // The third attempt's 4s backoff would overshoot the 3s budget, so it is refused outright.
+        assert!(policy
+            .should_retry(&RetryKind::Error(ErrorKind::ServerError))
+            .is_none());
+    }
+
+    #[test]
+    fn retry_predicate_can_force_stop() {
+        let conf = test_config().with_retry_predicate(|_kind, _attempts| RetryDecision::DoNotRetry);
+        let policy = Standard::new(conf).new_request_policy(None);
+        assert!(policy
+            .should_retry(&RetryKind::Error(ErrorKind::ServerError))
+            .is_none());
+    }
+
+    #[test]
+    fn retry_predicate_can_force_retry() {
+        let conf = test_config().with_retry_predicate(|_kind, _attempts| RetryDecision::Retry);
+        let policy = Standard::new(conf).new_request_policy(None);
+        let (_policy, dur) = policy
+            .should_retry(&RetryKind::Unnecessary)
+            .expect("predicate forces a retry even when the classifier would not");
+        assert_eq!(dur, Duration::from_secs(1));
+    }
+
+    #[test]
+    fn acquire_permit_is_noop_in_standard_mode() {
+        let policy = Standard::new(test_config()).new_request_policy(None);
+        assert_eq!(policy.acquire_permit(), Duration::ZERO);
+    }
+
+    #[test]
+    fn rate_limiter_enables_on_throttling() {
+        let limiter = ClientRateLimiter::new(0.0);
+        // While disabled the limiter imposes no delay.
+        assert_eq!(limiter.acquire(0.0, 1.0), Duration::ZERO);
+
+        // A throttling response enables the limiter and decreases the fill rate.
+        limiter.update(1.0, true);
+        let state = limiter.inner.lock().unwrap();
+        assert!(state.enabled);
+        // One send over the one second since the bucket started at 0.0 is a rate of 1.0, which
+        // smooths to a measured rate of `1.0 * SMOOTH = 0.8`,
+        // and a throttle applies the multiplicative `BETA` decrease: `0.8 * 0.7 = 0.56`.
+        assert!((state.measured_tx_rate - 0.8).abs() < 1e-9);
+        assert!((state.fill_rate - 0.56).abs() < 1e-9);
     }
 
     #[test]
@@ -492,7 +1234,7 @@ mod test {
 
         for (attempt, expected_backoff) in [initial_backoff, 2.0, 4.0].into_iter().enumerate() {
             let actual_backoff =
-                calculate_exponential_backoff(1.0, initial_backoff, attempt as u32);
+                calculate_exponential_backoff(1.0, initial_backoff, attempt as u32, 2.0);
             assert_eq!(expected_backoff, actual_backoff);
         }
     }
@@ -503,7 +1245,7 @@ mod test {
 
         for (attempt, expected_backoff) in [initial_backoff, 6.0, 12.0].into_iter().enumerate() {
             let actual_backoff =
-                calculate_exponential_backoff(1.0, initial_backoff, attempt as u32);
+                calculate_exponential_backoff(1.0, initial_backoff, attempt as u32, 2.0);
             assert_eq!(expected_backoff, actual_backoff);
         }
     }
@@ -514,7 +1256,7 @@ mod test {
 
         for (attempt, expected_backoff) in [initial_backoff, 0.06, 0.12].into_iter().enumerate() {
             let actual_backoff =
-                calculate_exponential_backoff(1.0, initial_backoff, attempt as u32);
+                calculate_exponential_backoff(1.0, initial_backoff, attempt as u32, 2.0);
             assert_eq!(expected_backoff, actual_backoff);
         }
     }